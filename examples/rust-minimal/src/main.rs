@@ -12,6 +12,7 @@ use std::error::Error;
 use std::fmt;
 use std::fs;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// Configuration validation error types
 #[derive(Debug)]
@@ -39,10 +40,222 @@ impl From<io::Error> for ConfigError {
     }
 }
 
+/// Expected type of a configuration value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ValueType {
+    String,
+    Integer,
+    Float,
+    Bool,
+}
+
+impl ValueType {
+    /// Check that `value` parses as this type.
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            ValueType::String => true,
+            ValueType::Integer => value.parse::<i64>().is_ok(),
+            ValueType::Float => value.parse::<f64>().is_ok(),
+            ValueType::Bool => matches!(value, "true" | "false"),
+        }
+    }
+}
+
+/// Constraints applied to a single configuration key.
+#[derive(Debug, Clone)]
+struct FieldSchema {
+    required: bool,
+    value_type: ValueType,
+    min: Option<f64>,
+    max: Option<f64>,
+    allowed: Option<Vec<String>>,
+    non_empty: bool,
+}
+
+impl FieldSchema {
+    /// Create an optional field of the given type with no extra constraints.
+    fn new(value_type: ValueType) -> Self {
+        FieldSchema {
+            required: false,
+            value_type,
+            min: None,
+            max: None,
+            allowed: None,
+            non_empty: false,
+        }
+    }
+
+    /// Mark this field as required.
+    fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Set an inclusive lower bound (numeric types only).
+    fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set an inclusive upper bound (numeric types only).
+    fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Restrict the value to one of the given allowed values.
+    fn allowed(mut self, values: &[&str]) -> Self {
+        self.allowed = Some(values.iter().map(|v| v.to_string()).collect());
+        self
+    }
+
+    /// Require a non-empty string value.
+    fn non_empty(mut self) -> Self {
+        self.non_empty = true;
+        self
+    }
+}
+
+/// A validation schema describing the constraints for each known key.
+#[derive(Debug, Default)]
+struct Schema {
+    fields: HashMap<String, FieldSchema>,
+}
+
+impl Schema {
+    /// Create a new empty schema.
+    fn new() -> Self {
+        Schema {
+            fields: HashMap::new(),
+        }
+    }
+
+    /// Register the constraints for a key.
+    fn field(mut self, key: &str, schema: FieldSchema) -> Self {
+        self.fields.insert(key.to_string(), schema);
+        self
+    }
+}
+
+/// Number of surrounding context lines shown around a diff hunk by default.
+const DIFF_CONTEXT_SIZE: usize = 3;
+
+/// A contiguous region where a configuration differs from a reference.
+///
+/// Mirrors rustfmt's `Mismatch`: `line_range` is the 1-based range of the
+/// hunk in the *expected* (reference) line listing, while `expected_lines`
+/// and `actual_lines` hold the reference and actual sides of the hunk
+/// (shared context lines appear in both).
+#[derive(Debug, PartialEq)]
+struct Mismatch {
+    line_range: (usize, usize),
+    expected_lines: Vec<String>,
+    actual_lines: Vec<String>,
+}
+
+/// One step of the line-level diff between two configurations.
+enum DiffOp {
+    /// Present and identical in both sides.
+    Equal(String),
+    /// Present in the expected side only (rendered with `-`).
+    Expected(String),
+    /// Present in the actual side only (rendered with `+`).
+    Actual(String),
+}
+
+/// Longest-common-subsequence diff over two line listings.
+fn diff_ops(expected: &[String], actual: &[String]) -> Vec<DiffOp> {
+    let (n, m) = (expected.len(), actual.len());
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if expected[i] == actual[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if expected[i] == actual[j] {
+            ops.push(DiffOp::Equal(expected[i].clone()));
+            i += 1;
+            j += 1;
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            ops.push(DiffOp::Expected(expected[i].clone()));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Actual(actual[j].clone()));
+            j += 1;
+        }
+    }
+    while i < n {
+        ops.push(DiffOp::Expected(expected[i].clone()));
+        i += 1;
+    }
+    while j < m {
+        ops.push(DiffOp::Actual(actual[j].clone()));
+        j += 1;
+    }
+    ops
+}
+
+/// Render diff hunks as `+`/`-`-prefixed text, each headed by
+/// `@@ -start,end @@` (the hunk's `line_range`, not a unified-diff count).
+fn format_diff(mismatches: &[Mismatch]) -> String {
+    let mut out = String::new();
+    for mismatch in mismatches {
+        let (start, end) = mismatch.line_range;
+        out.push_str(&format!("@@ -{},{} @@\n", start, end));
+
+        // Context lines are the common prefix/suffix of the two sides.
+        let exp = &mismatch.expected_lines;
+        let act = &mismatch.actual_lines;
+        let mut prefix = 0;
+        while prefix < exp.len() && prefix < act.len() && exp[prefix] == act[prefix] {
+            prefix += 1;
+        }
+        let mut suffix = 0;
+        while suffix < exp.len() - prefix
+            && suffix < act.len() - prefix
+            && exp[exp.len() - 1 - suffix] == act[act.len() - 1 - suffix]
+        {
+            suffix += 1;
+        }
+
+        for line in &exp[..prefix] {
+            out.push_str(&format!(" {}\n", line));
+        }
+        for line in &exp[prefix..exp.len() - suffix] {
+            out.push_str(&format!("-{}\n", line));
+        }
+        for line in &act[prefix..act.len() - suffix] {
+            out.push_str(&format!("+{}\n", line));
+        }
+        for line in &exp[exp.len() - suffix..] {
+            out.push_str(&format!(" {}\n", line));
+        }
+    }
+    out
+}
+
+/// Summary of a recursive [`Config::validate_tree`] run.
+#[derive(Debug, Default)]
+struct TreeReport {
+    passed: usize,
+    failed: usize,
+    failures: Vec<(PathBuf, Vec<ConfigError>)>,
+}
+
 /// Simple key-value configuration
 #[derive(Debug, PartialEq)]
 struct Config {
     entries: HashMap<String, String>,
+    /// 1-based source line each key was parsed from, for error context.
+    lines: HashMap<String, usize>,
 }
 
 impl Config {
@@ -50,22 +263,49 @@ impl Config {
     fn new() -> Self {
         Config {
             entries: HashMap::new(),
+            lines: HashMap::new(),
         }
     }
 
-    /// Parse configuration from string (simple KEY=VALUE format)
+    /// Parse configuration from string.
+    ///
+    /// Understands flat `KEY=VALUE` lines, `#` / `;` line comments, inline
+    /// trailing comments (`key=value ; note`), and `[section]` headers. Keys
+    /// that follow a header are namespaced with a dotted prefix, so a `host`
+    /// under `[database]` is stored as the composite key `database.host`.
     fn parse(content: &str) -> Result<Self, ConfigError> {
         let mut config = Config::new();
+        let mut section = String::new();
 
         for (line_num, line) in content.lines().enumerate() {
             let trimmed = line.trim();
 
-            // Skip empty lines and comments
-            if trimmed.is_empty() || trimmed.starts_with('#') {
+            // Skip empty lines and whole-line comments
+            if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+                continue;
+            }
+
+            // Section header: subsequent keys are namespaced under it. Strip
+            // any inline `#`/`;` comment first so `[section] ; note` is
+            // recognised rather than mistaken for a KEY=VALUE line.
+            let header = match trimmed.find(['#', ';']) {
+                Some(idx) => trimmed[..idx].trim(),
+                None => trimmed,
+            };
+            if header.starts_with('[') && header.ends_with(']') {
+                let name = header[1..header.len() - 1].trim();
+                if name.is_empty() {
+                    return Err(ConfigError::ParseError(format!(
+                        "Line {}: Section name cannot be empty",
+                        line_num + 1
+                    )));
+                }
+                section = name.to_string();
                 continue;
             }
 
-            // Parse KEY=VALUE
+            // Parse KEY=VALUE. The separator is the first `=`; anything quoted
+            // in the value (including `=` and comment chars) is handled below.
             let parts: Vec<&str> = trimmed.splitn(2, '=').collect();
             if parts.len() != 2 {
                 return Err(ConfigError::ParseError(format!(
@@ -75,7 +315,7 @@ impl Config {
             }
 
             let key = parts[0].trim();
-            let value = parts[1].trim();
+            let value = Config::unquote_value(parts[1], line_num + 1)?;
 
             if key.is_empty() {
                 return Err(ConfigError::ParseError(format!(
@@ -84,12 +324,87 @@ impl Config {
                 )));
             }
 
-            config.entries.insert(key.to_string(), value.to_string());
+            let composite = if section.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", section, key)
+            };
+
+            config.lines.insert(composite.clone(), line_num + 1);
+            config.entries.insert(composite, value);
         }
 
         Ok(config)
     }
 
+    /// Interpret the raw value text to the right of the first `=`.
+    ///
+    /// A value wrapped in matching single or double quotes is unquoted, with
+    /// `\n`, `\t`, `\\`, and `\"`/`\'` escapes processed; inside the quotes,
+    /// `=` and comment characters are treated literally. An unquoted value is
+    /// taken up to the first `#`/`;` inline comment. Unterminated quotes, bad
+    /// escapes, and trailing junk after a closing quote are reported as
+    /// `ParseError`s carrying `line_num`.
+    fn unquote_value(raw: &str, line_num: usize) -> Result<String, ConfigError> {
+        let raw = raw.trim();
+        let quote = match raw.chars().next() {
+            Some(c @ ('"' | '\'')) => c,
+            // Unquoted: strip any inline comment and trailing whitespace.
+            _ => {
+                let value = match raw.find(['#', ';']) {
+                    Some(idx) => raw[..idx].trim(),
+                    None => raw,
+                };
+                return Ok(value.to_string());
+            }
+        };
+
+        let mut out = String::new();
+        let mut chars = raw.chars();
+        chars.next(); // consume the opening quote
+        loop {
+            match chars.next() {
+                Some('\\') => match chars.next() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('\\') => out.push('\\'),
+                    Some('"') => out.push('"'),
+                    Some('\'') => out.push('\''),
+                    Some(other) => {
+                        return Err(ConfigError::ParseError(format!(
+                            "Line {}: Invalid escape sequence \\{}",
+                            line_num, other
+                        )));
+                    }
+                    None => {
+                        return Err(ConfigError::ParseError(format!(
+                            "Line {}: Unterminated escape sequence",
+                            line_num
+                        )));
+                    }
+                },
+                Some(c) if c == quote => {
+                    // Only whitespace or an inline comment may follow.
+                    let rest = chars.as_str().trim();
+                    if rest.is_empty() || rest.starts_with('#') || rest.starts_with(';') {
+                        return Ok(out);
+                    }
+                    return Err(ConfigError::ParseError(format!(
+                        "Line {}: Unexpected text after closing quote",
+                        line_num
+                    )));
+                }
+                Some(c) => out.push(c),
+                None => {
+                    return Err(ConfigError::ParseError(format!(
+                        "Line {}: Unterminated quoted value",
+                        line_num
+                    )));
+                }
+            }
+        }
+    }
+
     /// Validate configuration against required keys
     fn validate(&self, required_keys: &[&str]) -> Result<(), ConfigError> {
         for key in required_keys {
@@ -103,12 +418,344 @@ impl Config {
         Ok(())
     }
 
+    /// Validate configuration against a schema, collecting every violation.
+    ///
+    /// Unlike [`validate`](Self::validate), this does not bail on the first
+    /// problem: it returns one `ValidationError` per violation (a key that
+    /// breaks several constraints yields several), reporting the source line
+    /// and offending value so callers can fix the whole file in one pass.
+    fn validate_schema(&self, schema: &Schema) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        for (key, field) in &schema.fields {
+            let value = match self.entries.get(key) {
+                Some(value) => value,
+                None => {
+                    if field.required {
+                        errors.push(ConfigError::ValidationError(format!(
+                            "Missing required key: {}",
+                            key
+                        )));
+                    }
+                    continue;
+                }
+            };
+
+            // Prefix for present keys, pointing back at the source line.
+            let at = match self.lines.get(key) {
+                Some(line) => format!("Line {}, key {}", line, key),
+                None => format!("Key {}", key),
+            };
+
+            if field.non_empty && value.is_empty() {
+                errors.push(ConfigError::ValidationError(format!(
+                    "{}: value must not be empty",
+                    at
+                )));
+            }
+
+            if !field.value_type.accepts(value) {
+                errors.push(ConfigError::ValidationError(format!(
+                    "{}: value {:?} is not a valid {:?}",
+                    at, value, field.value_type
+                )));
+                // Range/enum checks below rely on a well-typed value.
+                continue;
+            }
+
+            if let Some(allowed) = &field.allowed {
+                if !allowed.iter().any(|a| a == value) {
+                    errors.push(ConfigError::ValidationError(format!(
+                        "{}: value {:?} is not one of {:?}",
+                        at, value, allowed
+                    )));
+                }
+            }
+
+            if field.min.is_some() || field.max.is_some() {
+                if let Ok(number) = value.parse::<f64>() {
+                    if let Some(min) = field.min {
+                        if number < min {
+                            errors.push(ConfigError::ValidationError(format!(
+                                "{}: value {} is below minimum {}",
+                                at, number, min
+                            )));
+                        }
+                    }
+                    if let Some(max) = field.max {
+                        if number > max {
+                            errors.push(ConfigError::ValidationError(format!(
+                                "{}: value {} is above maximum {}",
+                                at, number, max
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Render this configuration as a sorted list of `key=value` lines.
+    fn sorted_lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        lines.sort();
+        lines
+    }
+
+    /// Diff this configuration against a reference `expected` configuration.
+    ///
+    /// Computes a longest-common-subsequence over the two sorted key listings
+    /// and groups each run of changes — missing, extra, or value-changed keys —
+    /// into a [`Mismatch`] carrying up to `context` lines of surrounding
+    /// context (see [`DIFF_CONTEXT_SIZE`] for the usual default).
+    fn diff(&self, expected: &Config, context: usize) -> Vec<Mismatch> {
+        let expected_lines = expected.sorted_lines();
+        let actual_lines = self.sorted_lines();
+        let ops = diff_ops(&expected_lines, &actual_lines);
+
+        // 1-based expected line number for each op (advances on Equal/Expected).
+        let mut exp_no = Vec::with_capacity(ops.len());
+        let mut counter = 0usize;
+        for op in &ops {
+            match op {
+                DiffOp::Actual(_) => exp_no.push(counter),
+                _ => {
+                    counter += 1;
+                    exp_no.push(counter);
+                }
+            }
+        }
+
+        let is_change = |op: &DiffOp| !matches!(op, DiffOp::Equal(_));
+        let mut mismatches = Vec::new();
+        let mut idx = 0;
+        while idx < ops.len() {
+            if !is_change(&ops[idx]) {
+                idx += 1;
+                continue;
+            }
+            // Maximal run of consecutive changes.
+            let run_start = idx;
+            while idx < ops.len() && is_change(&ops[idx]) {
+                idx += 1;
+            }
+            let run_end = idx;
+
+            // Context stops at a neighbouring run, so each change lands in
+            // exactly one hunk and the context is pure Equal lines.
+            let mut start = run_start;
+            while start > 0 && run_start - start < context && !is_change(&ops[start - 1]) {
+                start -= 1;
+            }
+            let mut end = run_end;
+            while end < ops.len() && end - run_end < context && !is_change(&ops[end]) {
+                end += 1;
+            }
+
+            let mut exp_hunk = Vec::new();
+            let mut act_hunk = Vec::new();
+            for op in &ops[start..end] {
+                match op {
+                    DiffOp::Equal(line) => {
+                        exp_hunk.push(line.clone());
+                        act_hunk.push(line.clone());
+                    }
+                    DiffOp::Expected(line) => exp_hunk.push(line.clone()),
+                    DiffOp::Actual(line) => act_hunk.push(line.clone()),
+                }
+            }
+
+            let first = exp_no[start].max(1);
+            let last = exp_no[end - 1].max(first);
+            mismatches.push(Mismatch {
+                line_range: (first, last),
+                expected_lines: exp_hunk,
+                actual_lines: act_hunk,
+            });
+        }
+        mismatches
+    }
+
+    /// Check every ```` ```config ```` block embedded in a Markdown file.
+    ///
+    /// Walks the file line by line, tracking fence open/close, and runs each
+    /// fenced `config` block through [`parse`](Self::parse) and, when supplied,
+    /// [`validate_schema`](Self::validate_schema). Failures are reported as
+    /// `(line, error)` pairs where `line` is the 1-based line of the block's
+    /// opening fence, so errors point back at the doc. A file that cannot be
+    /// read is reported as a single `(0, IoError)` pair.
+    fn check_markdown(path: &Path, schema: Option<&Schema>) -> Result<(), Vec<(u32, ConfigError)>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => return Err(vec![(0, ConfigError::IoError(e))]),
+        };
+
+        let mut errors = Vec::new();
+        let mut in_block = false;
+        let mut fence_line = 0u32;
+        let mut block = String::new();
+
+        for (idx, line) in content.lines().enumerate() {
+            let line_num = (idx + 1) as u32;
+            let trimmed = line.trim_start();
+
+            if !in_block {
+                if trimmed == "```config" {
+                    in_block = true;
+                    fence_line = line_num;
+                    block.clear();
+                }
+                continue;
+            }
+
+            // Inside a block: the next line starting with ``` closes it.
+            if trimmed.starts_with("```") {
+                match Config::parse(&block) {
+                    Ok(config) => {
+                        if let Some(schema) = schema {
+                            if let Err(violations) = config.validate_schema(schema) {
+                                errors.extend(violations.into_iter().map(|e| (fence_line, e)));
+                            }
+                        }
+                    }
+                    Err(e) => errors.push((fence_line, e)),
+                }
+                in_block = false;
+                continue;
+            }
+
+            block.push_str(line);
+            block.push('\n');
+        }
+
+        // A block left open at EOF is a malformed doc, not a silently valid one.
+        if in_block {
+            errors.push((
+                fence_line,
+                ConfigError::ParseError("Unterminated ```config block".to_string()),
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Recursively validate every `ext` file under `root` against `schema`.
+    ///
+    /// Walks the directory tree the way the rustfmt test harness discovers its
+    /// fixtures, parsing and schema-validating each matching file and tallying
+    /// pass/fail counts alongside the per-file errors. Paths in `skip` (matched
+    /// by equality) are excluded so known-bad fixtures don't fail the run — the
+    /// `skip` parameter extends the requested `(root, ext, schema)` signature to
+    /// satisfy that skip-list requirement.
+    fn validate_tree(root: &Path, ext: &str, schema: &Schema, skip: &[&Path]) -> TreeReport {
+        let ext = ext.trim_start_matches('.');
+        let mut report = TreeReport::default();
+        Config::walk_tree(root, ext, schema, skip, &mut report);
+        report
+    }
+
+    /// Recursive worker for [`validate_tree`](Self::validate_tree).
+    fn walk_tree(dir: &Path, ext: &str, schema: &Schema, skip: &[&Path], report: &mut TreeReport) {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                report.failed += 1;
+                report
+                    .failures
+                    .push((dir.to_path_buf(), vec![ConfigError::IoError(e)]));
+                return;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if skip.iter().any(|s| *s == path.as_path()) {
+                continue;
+            }
+
+            if path.is_dir() {
+                Config::walk_tree(&path, ext, schema, skip, report);
+                continue;
+            }
+
+            if path.extension().and_then(|e| e.to_str()) != Some(ext) {
+                continue;
+            }
+
+            match fs::read_to_string(&path).map_err(ConfigError::from) {
+                Ok(content) => match Config::parse(&content) {
+                    Ok(config) => match config.validate_schema(schema) {
+                        Ok(()) => report.passed += 1,
+                        Err(violations) => {
+                            report.failed += 1;
+                            report.failures.push((path, violations));
+                        }
+                    },
+                    Err(e) => {
+                        report.failed += 1;
+                        report.failures.push((path, vec![e]));
+                    }
+                },
+                Err(e) => {
+                    report.failed += 1;
+                    report.failures.push((path, vec![e]));
+                }
+            }
+        }
+    }
+
     /// Get a value by key
     fn get(&self, key: &str) -> Option<&String> {
         self.entries.get(key)
     }
 }
 
+/// Reference configuration the example diffs a failing file against.
+fn reference_config() -> Config {
+    Config::parse("name=example\nport=8080\nversion=1.0")
+        .expect("built-in reference config must parse")
+}
+
+/// Schema the example validator demonstrates against a config file.
+fn example_schema() -> Schema {
+    Schema::new()
+        .field(
+            "name",
+            FieldSchema::new(ValueType::String).required().non_empty(),
+        )
+        .field(
+            "version",
+            FieldSchema::new(ValueType::String).required().non_empty(),
+        )
+        .field(
+            "port",
+            FieldSchema::new(ValueType::Integer).min(1.0).max(65535.0),
+        )
+        .field(
+            "mode",
+            FieldSchema::new(ValueType::String).allowed(&["debug", "release"]),
+        )
+        .field(
+            "sample_rate",
+            FieldSchema::new(ValueType::Float).min(0.0).max(1.0),
+        )
+        .field("verbose", FieldSchema::new(ValueType::Bool))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let args: Vec<String> = std::env::args().collect();
 
@@ -120,6 +767,48 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     let filename = &args[1];
+    let schema = example_schema();
+
+    // Directory mode: recursively validate every matching file under a root.
+    let path = Path::new(filename);
+    if path.is_dir() {
+        let ext = args.get(2).map(String::as_str).unwrap_or("conf");
+        let report = Config::validate_tree(path, ext, &schema, &[]);
+        println!(
+            "Validated {} file(s): {} passed, {} failed",
+            report.passed + report.failed,
+            report.passed,
+            report.failed
+        );
+        for (file, errors) in &report.failures {
+            eprintln!("❌ {}", file.display());
+            for error in errors {
+                eprintln!("  - {}", error);
+            }
+        }
+        if report.failed > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Markdown mode: validate every ```config block embedded in a doc.
+    if filename.ends_with(".md") {
+        match Config::check_markdown(Path::new(filename), Some(&schema)) {
+            Ok(()) => {
+                println!("✅ All config blocks in {} are valid!", filename);
+                return Ok(());
+            }
+            Err(errors) => {
+                eprintln!("❌ {} config block(s) failed:", errors.len());
+                for (line, error) in &errors {
+                    eprintln!("  - line {}: {}", line, error);
+                }
+                std::process::exit(1);
+            }
+        }
+    }
+
     let content = fs::read_to_string(filename)?;
     let config = Config::parse(&content)?;
 
@@ -127,6 +816,22 @@ fn main() -> Result<(), Box<dyn Error>> {
     let required = ["name", "version"];
     config.validate(&required)?;
 
+    // Schema validation: type/range/enum constraints, collecting every issue.
+    if let Err(violations) = config.validate_schema(&schema) {
+        eprintln!("❌ Configuration failed schema validation:");
+        for violation in &violations {
+            eprintln!("  - {}", violation);
+        }
+
+        // Show what differs relative to the reference config.
+        let mismatches = config.diff(&reference_config(), DIFF_CONTEXT_SIZE);
+        if !mismatches.is_empty() {
+            eprintln!("\nDifferences from the reference configuration:");
+            eprint!("{}", format_diff(&mismatches));
+        }
+        std::process::exit(1);
+    }
+
     println!("✅ Configuration valid!");
     println!("\nParsed {} entries:", config.entries.len());
     for (key, value) in &config.entries {
@@ -162,10 +867,143 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_sections_and_comments() {
+        let input = "; file comment\nname=test\n[database] ; the db section\nhost=localhost ; inline\nport=5432";
+        let config = Config::parse(input).unwrap();
+        assert_eq!(config.get("name"), Some(&"test".to_string()));
+        assert_eq!(config.get("database.host"), Some(&"localhost".to_string()));
+        assert_eq!(config.get("database.port"), Some(&"5432".to_string()));
+    }
+
     #[test]
     fn test_validation_success() {
         let input = "name=test\nversion=1.0";
         let config = Config::parse(input).unwrap();
         assert!(config.validate(&["name", "version"]).is_ok());
     }
+
+    #[test]
+    fn test_validate_schema_collects_all() {
+        let input = "name=\nport=99999\nmode=fast";
+        let config = Config::parse(input).unwrap();
+        let schema = Schema::new()
+            .field("name", FieldSchema::new(ValueType::String).required().non_empty())
+            .field("port", FieldSchema::new(ValueType::Integer).min(1.0).max(65535.0))
+            .field("mode", FieldSchema::new(ValueType::String).allowed(&["slow", "medium"]))
+            .field("version", FieldSchema::new(ValueType::String).required());
+        let errors = config.validate_schema(&schema).unwrap_err();
+        // empty name, out-of-range port, disallowed mode, missing version
+        assert_eq!(errors.len(), 4);
+    }
+
+    #[test]
+    fn test_validate_schema_ok() {
+        let input = "port=8080\nmode=slow";
+        let config = Config::parse(input).unwrap();
+        let schema = Schema::new()
+            .field("port", FieldSchema::new(ValueType::Integer).min(1.0).max(65535.0))
+            .field("mode", FieldSchema::new(ValueType::String).allowed(&["slow", "fast"]));
+        assert!(config.validate_schema(&schema).is_ok());
+    }
+
+    #[test]
+    fn test_diff_detects_missing_extra_and_changed() {
+        let expected = Config::parse("name=app\nport=8080\nversion=1.0").unwrap();
+        let actual = Config::parse("name=app\nport=9090\ndebug=true").unwrap();
+        let mismatches = actual.diff(&expected, DIFF_CONTEXT_SIZE);
+        let rendered = format_diff(&mismatches);
+        // changed port
+        assert!(rendered.contains("-port=8080"));
+        assert!(rendered.contains("+port=9090"));
+        // extra key in actual
+        assert!(rendered.contains("+debug=true"));
+        // missing key from actual
+        assert!(rendered.contains("-version=1.0"));
+    }
+
+    #[test]
+    fn test_diff_identical_is_empty() {
+        let config = Config::parse("a=1\nb=2").unwrap();
+        assert!(config.diff(&config, DIFF_CONTEXT_SIZE).is_empty());
+    }
+
+    #[test]
+    fn test_check_markdown_flags_bad_block() {
+        let md = "# Doc\n\n```config\nname=ok\n```\n\nprose\n\n```config\nbroken line\n```\n";
+        let path = std::env::temp_dir().join("rsr_check_markdown.md");
+        fs::write(&path, md).unwrap();
+        let errors = Config::check_markdown(&path, None).unwrap_err();
+        let _ = fs::remove_file(&path);
+        // Only the second block (opening fence on line 9) fails to parse.
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 9);
+    }
+
+    #[test]
+    fn test_check_markdown_unterminated_block() {
+        let md = "# Doc\n\n```config\nname=ok\n";
+        let path = std::env::temp_dir().join("rsr_check_markdown_unterminated.md");
+        fs::write(&path, md).unwrap();
+        let errors = Config::check_markdown(&path, None).unwrap_err();
+        let _ = fs::remove_file(&path);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 3);
+    }
+
+    #[test]
+    fn test_validate_tree_reports_and_skips() {
+        let root = std::env::temp_dir().join("rsr_validate_tree");
+        let nested = root.join("nested");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join("good.conf"), "name=a").unwrap();
+        fs::write(nested.join("bad.conf"), "name=").unwrap();
+        let known_bad = nested.join("ignored.conf");
+        fs::write(&known_bad, "name=").unwrap();
+        fs::write(root.join("notes.txt"), "name=").unwrap();
+
+        let schema = Schema::new()
+            .field("name", FieldSchema::new(ValueType::String).required().non_empty());
+        let report = Config::validate_tree(&root, "conf", &schema, &[known_bad.as_path()]);
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(report.passed, 1); // good.conf
+        assert_eq!(report.failed, 1); // nested/bad.conf (notes.txt and ignored.conf excluded)
+    }
+
+    #[test]
+    fn test_parse_quoted_values_and_escapes() {
+        let input = "msg=\"a = b ; still value\"\npath=\"C:\\\\dir\"\nnote='line\\nbreak'";
+        let config = Config::parse(input).unwrap();
+        assert_eq!(config.get("msg"), Some(&"a = b ; still value".to_string()));
+        assert_eq!(config.get("path"), Some(&"C:\\dir".to_string()));
+        assert_eq!(config.get("note"), Some(&"line\nbreak".to_string()));
+    }
+
+    #[test]
+    fn test_parse_quoted_with_trailing_comment() {
+        let config = Config::parse("key=\"value\" # trailing").unwrap();
+        assert_eq!(config.get("key"), Some(&"value".to_string()));
+    }
+
+    #[test]
+    fn test_parse_malformed_quoting() {
+        assert!(Config::parse("key=\"unterminated").is_err());
+        assert!(Config::parse("key=\"bad\\x\"").is_err());
+        assert!(Config::parse("key=\"value\" junk").is_err());
+    }
+
+    #[test]
+    fn test_validate_schema_float_and_bool() {
+        let input = "ratio=0.5\ndebug=true";
+        let config = Config::parse(input).unwrap();
+        let schema = Schema::new()
+            .field("ratio", FieldSchema::new(ValueType::Float).min(0.0).max(1.0))
+            .field("debug", FieldSchema::new(ValueType::Bool));
+        assert!(config.validate_schema(&schema).is_ok());
+
+        let bad = Config::parse("ratio=nope\ndebug=maybe").unwrap();
+        assert_eq!(bad.validate_schema(&schema).unwrap_err().len(), 2);
+    }
 }